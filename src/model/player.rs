@@ -11,3 +11,20 @@ pub struct Player {
     pub next_nuclear_strike_x: f64,
     pub next_nuclear_strike_y: f64,
 }
+
+impl Default for Player {
+    fn default() -> Self {
+        Player {
+            id: 0,
+            me: false,
+            strategy_crashed: false,
+            score: 0,
+            remaining_action_cooldown_ticks: 0,
+            remaining_nuclear_strike_cooldown_ticks: 0,
+            next_nuclear_strike_vehicle_id: -1,
+            next_nuclear_strike_tick_index: -1,
+            next_nuclear_strike_x: 0.0,
+            next_nuclear_strike_y: 0.0,
+        }
+    }
+}
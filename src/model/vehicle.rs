@@ -3,7 +3,7 @@ use super::unit::Unit;
 use super::vehicle_type::VehicleType;
 use super::vehicle_update::VehicleUpdate;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct Vehicle {
     pub id: i64,
     pub x: f64,
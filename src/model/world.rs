@@ -5,7 +5,7 @@ use super::vehicle::Vehicle;
 use super::vehicle_update::VehicleUpdate;
 use super::weather_type::WeatherType;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct World {
     pub tick_index: i32,
     pub tick_count: i32,